@@ -1,7 +1,27 @@
 use crate::Cfg;
+use alloc::collections::BTreeMap;
 use core::fmt::Debug;
 use core::hash::Hash;
+use primitives::Address;
 use specification::constants::MAX_CODE_SIZE;
+use specification::hardfork::SpecId;
+
+/// EVM call stack depth limit.
+///
+/// This is the default used when [`CfgEnv::call_stack_limit`] is `None`.
+pub const CALL_STACK_LIMIT: usize = 1024;
+
+/// Maximum nonce value permitted by [EIP-2681](https://eips.ethereum.org/EIPS/eip-2681)
+/// (`2^64 - 1`); incrementing a nonce already at this value would reach the forbidden
+/// `2^64`.
+pub const MAX_NONCE: u64 = u64::MAX;
+
+/// Error returned when incrementing a nonce would exceed the EIP-2681 cap.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum NonceOverflowError {
+    /// The account's nonce is already at [`MAX_NONCE`] and cannot be incremented.
+    MaxNonce,
+}
 
 /// What bytecode analysis to perform.
 #[derive(Clone, Default, Debug, Eq, PartialEq, Hash)]
@@ -14,6 +34,258 @@ pub enum AnalysisKind {
     Analyse,
 }
 
+/// Override table for individual opcode gas costs.
+///
+/// Every field is optional; any member left as `None` falls back to the cost derived
+/// from the active [`SpecId`]. Call [`GasSchedule::resolve`] once per spec activation
+/// to turn this into an [`EffectiveGasSchedule`] with every field populated, so opcode
+/// handlers can index into it directly instead of performing an `Option` check in the
+/// hot path.
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasSchedule {
+    /// Cost of the `SLOAD` opcode.
+    pub sload_gas: Option<u64>,
+    /// Cost of an `SSTORE` that sets a slot from zero to a non-zero value.
+    pub sstore_set_gas: Option<u64>,
+    /// Cost of an `SSTORE` that resets a slot to a different non-zero value.
+    pub sstore_reset_gas: Option<u64>,
+    /// Cost per byte of the exponent in the `EXP` opcode.
+    pub exp_byte_gas: Option<u64>,
+    /// Base cost of the `CALL` opcode.
+    pub call_gas: Option<u64>,
+    /// Additional cost of a `CALL` that creates a new account.
+    pub call_new_account_gas: Option<u64>,
+    /// Cost of the `EXTCODEHASH` opcode.
+    pub extcodehash_gas: Option<u64>,
+    /// Cost of the `SELFDESTRUCT` opcode.
+    pub suicide_gas: Option<u64>,
+    /// Cost per topic of the `LOG*` opcodes.
+    pub log_topic_gas: Option<u64>,
+    /// Cost per non-zero byte of transaction calldata.
+    pub tx_data_non_zero_gas: Option<u64>,
+    /// EIP-2929 cost of a cold `SLOAD`.
+    pub cold_sload_cost: Option<u64>,
+    /// EIP-2929 cost of a cold account access.
+    pub cold_account_access_cost: Option<u64>,
+    /// EIP-2929 cost of a warm storage read.
+    pub warm_storage_read_cost: Option<u64>,
+}
+
+/// A [`GasSchedule`] resolved against a [`SpecId`]: every field is populated, either
+/// from the override table or from the spec's built-in cost, so opcode gas-charging
+/// paths can read from it directly.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct EffectiveGasSchedule {
+    /// Cost of the `SLOAD` opcode.
+    pub sload_gas: u64,
+    /// Cost of an `SSTORE` that sets a slot from zero to a non-zero value.
+    pub sstore_set_gas: u64,
+    /// Cost of an `SSTORE` that resets a slot to a different non-zero value.
+    pub sstore_reset_gas: u64,
+    /// Cost per byte of the exponent in the `EXP` opcode.
+    pub exp_byte_gas: u64,
+    /// Base cost of the `CALL` opcode.
+    pub call_gas: u64,
+    /// Additional cost of a `CALL` that creates a new account.
+    pub call_new_account_gas: u64,
+    /// Cost of the `EXTCODEHASH` opcode.
+    pub extcodehash_gas: u64,
+    /// Cost of the `SELFDESTRUCT` opcode.
+    pub suicide_gas: u64,
+    /// Cost per topic of the `LOG*` opcodes.
+    pub log_topic_gas: u64,
+    /// Cost per non-zero byte of transaction calldata.
+    pub tx_data_non_zero_gas: u64,
+    /// EIP-2929 cost of a cold `SLOAD`.
+    pub cold_sload_cost: u64,
+    /// EIP-2929 cost of a cold account access.
+    pub cold_account_access_cost: u64,
+    /// EIP-2929 cost of a warm storage read.
+    pub warm_storage_read_cost: u64,
+}
+
+impl GasSchedule {
+    /// Resolves this override table against `spec_id`, filling in every unset member
+    /// with the cost the spec would otherwise use. This is meant to be called once per
+    /// spec activation; the result is what opcode handlers should index into.
+    pub fn resolve(&self, spec_id: SpecId) -> EffectiveGasSchedule {
+        let berlin = spec_id.is_enabled_in(SpecId::BERLIN);
+        let istanbul = spec_id.is_enabled_in(SpecId::ISTANBUL);
+        let spurious_dragon = spec_id.is_enabled_in(SpecId::SPURIOUS_DRAGON);
+        let tangerine = spec_id.is_enabled_in(SpecId::TANGERINE);
+
+        EffectiveGasSchedule {
+            sload_gas: self.sload_gas.unwrap_or(if istanbul {
+                800
+            } else if tangerine {
+                200
+            } else {
+                50
+            }),
+            sstore_set_gas: self.sstore_set_gas.unwrap_or(20_000),
+            sstore_reset_gas: self.sstore_reset_gas.unwrap_or(5_000),
+            exp_byte_gas: self.exp_byte_gas.unwrap_or(if spurious_dragon { 50 } else { 10 }),
+            call_gas: self.call_gas.unwrap_or(if tangerine { 700 } else { 40 }),
+            call_new_account_gas: self.call_new_account_gas.unwrap_or(25_000),
+            extcodehash_gas: self
+                .extcodehash_gas
+                .unwrap_or(if istanbul { 700 } else { 400 }),
+            suicide_gas: self.suicide_gas.unwrap_or(if tangerine { 5_000 } else { 0 }),
+            log_topic_gas: self.log_topic_gas.unwrap_or(375),
+            tx_data_non_zero_gas: self
+                .tx_data_non_zero_gas
+                .unwrap_or(if istanbul { 16 } else { 68 }),
+            cold_sload_cost: self.cold_sload_cost.unwrap_or(if berlin { 2_100 } else { 0 }),
+            cold_account_access_cost: self
+                .cold_account_access_cost
+                .unwrap_or(if berlin { 2_600 } else { 0 }),
+            warm_storage_read_cost: self
+                .warm_storage_read_cost
+                .unwrap_or(if berlin { 100 } else { 0 }),
+        }
+    }
+}
+
+/// Patch controlling account initialization and empty-account pruning semantics.
+///
+/// Every field is optional; `None` falls back to post-EIP-161 mainnet behavior,
+/// whatever the active [`SpecId`]. This lets chains that predate EIP-161, or that kept empty
+/// accounts alive, opt out of revm's hardcoded defaults. Account-loading, CREATE, and
+/// state-clearing call sites should read the resolved values through
+/// [`AccountPatch::initial_nonce`], [`AccountPatch::create_nonce`], and
+/// [`AccountPatch::should_prune_empty_account`] rather than the raw `Option` fields.
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountPatch {
+    /// Nonce assigned to freshly created accounts. `None` falls back to `0`.
+    pub initial_nonce: Option<u64>,
+    /// Nonce assigned to an account on CREATE (EIP-161.a). `None` falls back to `1`.
+    pub initial_create_nonce: Option<u64>,
+    /// Whether empty accounts are considered to exist and thus are not pruned
+    /// (EIP-161.b/c/d). `None` falls back to `false` (mainnet: empty accounts are
+    /// pruned).
+    pub empty_considered_exists: Option<bool>,
+}
+
+impl AccountPatch {
+    /// Nonce a freshly created account should start with.
+    pub fn initial_nonce(&self) -> u64 {
+        self.initial_nonce.unwrap_or(0)
+    }
+
+    /// Nonce an account should have right after a CREATE (EIP-161.a).
+    pub fn create_nonce(&self) -> u64 {
+        self.initial_create_nonce.unwrap_or(1)
+    }
+
+    /// Whether empty accounts survive state clearing instead of being pruned
+    /// (EIP-161.b/c/d).
+    pub fn empty_considered_exists(&self) -> bool {
+        self.empty_considered_exists.unwrap_or(false)
+    }
+
+    /// Returns `true` if an account with the given balance/nonce/code-presence is
+    /// "empty" per EIP-161 and this patch says empty accounts should be pruned.
+    pub fn should_prune_empty_account(
+        &self,
+        balance_is_zero: bool,
+        nonce_is_zero: bool,
+        has_code: bool,
+    ) -> bool {
+        balance_is_zero && nonce_is_zero && !has_code && !self.empty_considered_exists()
+    }
+}
+
+/// Per-address override applied on top of the precompiles the active [`SpecId`] would
+/// otherwise install.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrecompileActivation {
+    /// Install the precompile at this address even if the active spec doesn't have it,
+    /// using the implementation already registered for that address.
+    Enabled,
+    /// Remove the precompile at this address even if the active spec installs it.
+    Disabled,
+    /// At this address, install the implementation registered for the given address
+    /// instead of the spec's default (or absence of one).
+    Remapped(Address),
+}
+
+/// Applies `overrides` on top of `base`, the set of precompile addresses the active
+/// spec would otherwise install (mapped to the address whose implementation they use,
+/// normally themselves). Returns the final active set the handler should install.
+pub fn apply_precompile_overrides(
+    base: &BTreeMap<Address, Address>,
+    overrides: &BTreeMap<Address, PrecompileActivation>,
+) -> BTreeMap<Address, Address> {
+    let mut active = base.clone();
+    for (address, activation) in overrides {
+        match activation {
+            PrecompileActivation::Enabled => {
+                active.entry(*address).or_insert(*address);
+            }
+            PrecompileActivation::Disabled => {
+                active.remove(address);
+            }
+            PrecompileActivation::Remapped(target) => {
+                active.insert(*address, *target);
+            }
+        }
+    }
+    active
+}
+
+/// Selects whether the EVM tracks a single scalar gas amount or a vector of
+/// independently priced gas dimensions.
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GasComputationMode {
+    /// Track a single scalar gas amount, as on Ethereum mainnet.
+    #[default]
+    SingleDimensional,
+    /// Track an additional L1/DA gas dimension alongside the L2/compute dimension,
+    /// each with its own limit and price, combined into a single fee at settlement.
+    MultiDimensional {
+        /// Whether the L2/compute dimension is charged in addition to the L1/DA one.
+        include_l2_gas: bool,
+        /// Whether the L1/DA dimension is priced using KZG blob gas accounting.
+        use_kzg_da: bool,
+    },
+}
+
+/// Per-dimension gas amounts tracked by the executor when
+/// [`GasComputationMode::MultiDimensional`] is active. The interpreter still charges a
+/// single scalar gas amount for the compute dimension; `l1_gas`/`da_gas` are
+/// accumulated separately by the executor around it.
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasVector {
+    /// L1 settlement gas.
+    pub l1_gas: u64,
+    /// L2/compute gas. This is the dimension the 63/64 call-forwarding rule and gas
+    /// refunds apply to; `da_gas` must be excluded from both.
+    pub l2_gas: u64,
+    /// Data-availability/blob gas.
+    pub da_gas: u64,
+}
+
+impl GasVector {
+    /// Returns `true` if any dimension of `self` exceeds the corresponding dimension
+    /// of `limits`, meaning the transaction must fail.
+    pub fn exceeds_limit(&self, limits: &GasVector) -> bool {
+        self.l1_gas > limits.l1_gas || self.l2_gas > limits.l2_gas || self.da_gas > limits.da_gas
+    }
+
+    /// Combines each dimension's consumption with its price in `prices` into the total
+    /// settlement fee: `fee = Σ dimension_amount * dimension_price`.
+    pub fn fee(&self, prices: &GasVector) -> u128 {
+        self.l1_gas as u128 * prices.l1_gas as u128
+            + self.l2_gas as u128 * prices.l2_gas as u128
+            + self.da_gas as u128 * prices.da_gas as u128
+    }
+}
+
 /// EVM configuration.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -34,8 +306,17 @@ pub struct CfgEnv {
     /// If some it will effects EIP-170: Contract code size limit. Useful to increase this because of tests.
     /// By default it is 0x6000 (~25kb).
     pub limit_contract_code_size: Option<usize>,
+    /// If some it will change the call depth limit used for CALL/CREATE recursion checks.
+    /// By default it is 1024. CALL/CREATE handling should consult this via
+    /// [`CfgEnv::is_call_stack_limit_reached`] instead of a hardcoded depth check.
+    pub call_stack_limit: Option<usize>,
     /// Skips the nonce validation against the account's nonce.
     pub disable_nonce_check: bool,
+    /// Skips the EIP-2681 max nonce check (nonce must not exceed [`MAX_NONCE`]).
+    /// By default, it is set to `false`, meaning a sender or CREATE that would push a
+    /// nonce past the cap fails validation with [`NonceOverflowError::MaxNonce`]
+    /// instead of wrapping; see [`CfgEnv::checked_increment_nonce`].
+    pub disable_max_nonce_check: bool,
     /// A hard memory limit in bytes beyond which [crate::result::OutOfGasError::Memory] cannot be resized.
     ///
     /// In cases where the gas limit may be extraordinarily high, it is recommended to set this to
@@ -66,6 +347,36 @@ pub struct CfgEnv {
     /// By default, it is set to `false`.
     #[cfg(feature = "optional_no_base_fee")]
     pub disable_base_fee: bool,
+    /// Overrides individual opcode gas costs for the active hardfork.
+    ///
+    /// By default this is `None`, meaning every gas cost is derived purely from the
+    /// active [`SpecId`]. Resolve this via [`GasSchedule::resolve`] once per spec
+    /// activation to get the [`EffectiveGasSchedule`] that opcode handlers read from.
+    pub gas_schedule: Option<GasSchedule>,
+    /// Overrides account initialization and empty-account pruning semantics.
+    ///
+    /// By default this is `None`, meaning mainnet EIP-161 behavior for the active
+    /// spec. Unset members of the [`AccountPatch`] fall back to the post-EIP-161
+    /// mainnet values regardless of spec; see its
+    /// resolved accessors for the values account-loading/creation/state-clearing
+    /// should use.
+    pub account_patch: Option<AccountPatch>,
+    /// Activates, deactivates, or remaps individual precompiles on top of the set the
+    /// active spec would otherwise install.
+    ///
+    /// By default this is `None`, meaning the precompile set is determined purely by
+    /// the active [`SpecId`]. Handlers assembling the active precompile set should pass
+    /// it, along with the spec-derived set, through [`apply_precompile_overrides`].
+    pub precompile_overrides: Option<BTreeMap<Address, PrecompileActivation>>,
+    /// Selects single- or multi-dimensional gas accounting.
+    ///
+    /// By default this is [`GasComputationMode::SingleDimensional`], matching mainnet.
+    /// The interpreter always charges a scalar gas amount; in multi-dimensional mode
+    /// the executor should additionally accumulate a [`GasVector`] and use
+    /// [`GasVector::exceeds_limit`]/[`GasVector::fee`] to enforce per-dimension limits
+    /// and compute the settlement fee, excluding `da_gas` from the call-forwarding and
+    /// refund (63/64 rule) calculations that otherwise apply to `l2_gas`.
+    pub gas_computation_mode: GasComputationMode,
 }
 
 impl CfgEnv {
@@ -73,6 +384,37 @@ impl CfgEnv {
         self.chain_id = chain_id;
         self
     }
+
+    /// Returns `true` if `depth` has reached this config's call stack limit, meaning
+    /// the CALL/CREATE that would produce it must be rejected instead of recursing
+    /// further.
+    pub fn is_call_stack_limit_reached(&self, depth: usize) -> bool {
+        depth >= Cfg::call_stack_limit(self)
+    }
+
+    /// Applies [`CfgEnv::precompile_overrides`] (if any) on top of `base`, the
+    /// spec-derived precompile set, returning the final active set.
+    pub fn active_precompiles(&self, base: &BTreeMap<Address, Address>) -> BTreeMap<Address, Address> {
+        match &self.precompile_overrides {
+            Some(overrides) => apply_precompile_overrides(base, overrides),
+            None => base.clone(),
+        }
+    }
+
+    /// Increments `nonce` by one for sender validation or CREATE, enforcing the
+    /// EIP-2681 cap unless [`CfgEnv::disable_max_nonce_check`] is set.
+    ///
+    /// Returns [`NonceOverflowError::MaxNonce`] instead of wrapping when the nonce is
+    /// already at [`MAX_NONCE`] and the check is enabled.
+    pub fn checked_increment_nonce(&self, nonce: u64) -> Result<u64, NonceOverflowError> {
+        if self.disable_max_nonce_check {
+            return Ok(nonce.wrapping_add(1));
+        }
+        if nonce >= MAX_NONCE {
+            return Err(NonceOverflowError::MaxNonce);
+        }
+        Ok(nonce + 1)
+    }
 }
 
 impl Cfg for CfgEnv {
@@ -84,6 +426,10 @@ impl Cfg for CfgEnv {
         self.limit_contract_code_size.unwrap_or(MAX_CODE_SIZE)
     }
 
+    fn call_stack_limit(&self) -> usize {
+        self.call_stack_limit.unwrap_or(CALL_STACK_LIMIT)
+    }
+
     fn is_eip3607_disabled(&self) -> bool {
         cfg_if::cfg_if! {
             if #[cfg(feature = "optional_eip3607")] {
@@ -128,6 +474,10 @@ impl Cfg for CfgEnv {
         self.disable_nonce_check
     }
 
+    fn is_max_nonce_check_disabled(&self) -> bool {
+        self.disable_max_nonce_check
+    }
+
     fn is_base_fee_check_disabled(&self) -> bool {
         cfg_if::cfg_if! {
             if #[cfg(feature = "optional_no_base_fee")] {
@@ -137,6 +487,22 @@ impl Cfg for CfgEnv {
             }
         }
     }
+
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        self.gas_schedule.as_ref()
+    }
+
+    fn account_patch(&self) -> Option<&AccountPatch> {
+        self.account_patch.as_ref()
+    }
+
+    fn precompile_overrides(&self) -> Option<&BTreeMap<Address, PrecompileActivation>> {
+        self.precompile_overrides.as_ref()
+    }
+
+    fn gas_computation_mode(&self) -> &GasComputationMode {
+        &self.gas_computation_mode
+    }
 }
 
 impl Default for CfgEnv {
@@ -145,7 +511,9 @@ impl Default for CfgEnv {
             chain_id: 1,
             perf_analyse_created_bytecodes: AnalysisKind::default(),
             limit_contract_code_size: None,
+            call_stack_limit: None,
             disable_nonce_check: false,
+            disable_max_nonce_check: false,
             #[cfg(any(feature = "c-kzg", feature = "kzg-rs"))]
             kzg_settings: crate::kzg::EnvKzgSettings::Default,
             #[cfg(feature = "memory_limit")]
@@ -160,6 +528,176 @@ impl Default for CfgEnv {
             disable_gas_refund: false,
             #[cfg(feature = "optional_no_base_fee")]
             disable_base_fee: false,
+            gas_schedule: None,
+            account_patch: None,
+            precompile_overrides: None,
+            gas_computation_mode: GasComputationMode::default(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gas_schedule_resolve_uses_spec_defaults_when_unset() {
+        let resolved = GasSchedule::default().resolve(SpecId::BERLIN);
+        assert_eq!(resolved.cold_sload_cost, 2_100);
+        assert_eq!(resolved.warm_storage_read_cost, 100);
+
+        let pre_berlin = GasSchedule::default().resolve(SpecId::ISTANBUL);
+        assert_eq!(pre_berlin.cold_sload_cost, 0);
+        assert_eq!(pre_berlin.sload_gas, 800);
+    }
+
+    #[test]
+    fn gas_schedule_resolve_pre_tangerine_defaults() {
+        let resolved = GasSchedule::default().resolve(SpecId::FRONTIER);
+        assert_eq!(resolved.sload_gas, 50);
+        assert_eq!(resolved.suicide_gas, 0);
+
+        let post_tangerine = GasSchedule::default().resolve(SpecId::TANGERINE);
+        assert_eq!(post_tangerine.sload_gas, 200);
+        assert_eq!(post_tangerine.suicide_gas, 5_000);
+    }
+
+    #[test]
+    fn gas_schedule_resolve_prefers_override() {
+        let schedule = GasSchedule {
+            sload_gas: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(schedule.resolve(SpecId::BERLIN).sload_gas, 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gas_schedule_serde_roundtrip() {
+        let schedule = GasSchedule {
+            sload_gas: Some(42),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&schedule).unwrap();
+        let decoded: GasSchedule = serde_json::from_str(&json).unwrap();
+        assert_eq!(schedule, decoded);
+    }
+
+    #[test]
+    fn call_stack_limit_defaults_to_1024() {
+        let cfg = CfgEnv::default();
+        assert_eq!(Cfg::call_stack_limit(&cfg), CALL_STACK_LIMIT);
+        assert!(!cfg.is_call_stack_limit_reached(1023));
+        assert!(cfg.is_call_stack_limit_reached(1024));
+    }
+
+    #[test]
+    fn call_stack_limit_override_is_consulted() {
+        let cfg = CfgEnv {
+            call_stack_limit: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(Cfg::call_stack_limit(&cfg), 2);
+        assert!(cfg.is_call_stack_limit_reached(2));
+        assert!(!cfg.is_call_stack_limit_reached(1));
+    }
+
+    #[test]
+    fn account_patch_defaults_match_mainnet_eip161() {
+        let patch = AccountPatch::default();
+        assert_eq!(patch.initial_nonce(), 0);
+        assert_eq!(patch.create_nonce(), 1);
+        assert!(!patch.empty_considered_exists());
+        assert!(patch.should_prune_empty_account(true, true, false));
+    }
+
+    #[test]
+    fn account_patch_empty_considered_exists_keeps_empty_accounts() {
+        let patch = AccountPatch {
+            empty_considered_exists: Some(true),
+            ..Default::default()
+        };
+        assert!(!patch.should_prune_empty_account(true, true, false));
+    }
+
+    fn addr(byte: u8) -> Address {
+        Address::with_last_byte(byte)
+    }
+
+    #[test]
+    fn precompile_overrides_enable_disable_and_remap() {
+        let base = BTreeMap::from([(addr(1), addr(1)), (addr(2), addr(2))]);
+        let mut overrides = BTreeMap::new();
+        overrides.insert(addr(2), PrecompileActivation::Disabled);
+        overrides.insert(addr(3), PrecompileActivation::Enabled);
+        overrides.insert(addr(1), PrecompileActivation::Remapped(addr(9)));
+
+        let active = apply_precompile_overrides(&base, &overrides);
+        assert_eq!(active.get(&addr(1)), Some(&addr(9)));
+        assert_eq!(active.get(&addr(2)), None);
+        assert_eq!(active.get(&addr(3)), Some(&addr(3)));
+    }
+
+    #[test]
+    fn cfg_env_active_precompiles_without_overrides_is_unchanged() {
+        let base = BTreeMap::from([(addr(1), addr(1))]);
+        let cfg = CfgEnv::default();
+        assert_eq!(cfg.active_precompiles(&base), base);
+    }
+
+    #[test]
+    fn checked_increment_nonce_rejects_max_nonce_by_default() {
+        let cfg = CfgEnv::default();
+        // 2^64 - 2 is a legal nonce and must still be incrementable to 2^64 - 1.
+        assert_eq!(cfg.checked_increment_nonce(u64::MAX - 1), Ok(u64::MAX));
+        assert_eq!(cfg.checked_increment_nonce(MAX_NONCE - 1), Ok(MAX_NONCE));
+        assert_eq!(
+            cfg.checked_increment_nonce(MAX_NONCE),
+            Err(NonceOverflowError::MaxNonce)
+        );
+    }
+
+    #[test]
+    fn checked_increment_nonce_wraps_when_disabled() {
+        let cfg = CfgEnv {
+            disable_max_nonce_check: true,
+            ..Default::default()
+        };
+        assert_eq!(cfg.checked_increment_nonce(u64::MAX), Ok(0));
+    }
+
+    #[test]
+    fn gas_vector_exceeds_limit_checks_each_dimension() {
+        let limits = GasVector {
+            l1_gas: 10,
+            l2_gas: 10,
+            da_gas: 10,
+        };
+        assert!(!GasVector { l1_gas: 10, l2_gas: 10, da_gas: 10 }.exceeds_limit(&limits));
+        assert!(GasVector { l1_gas: 11, l2_gas: 0, da_gas: 0 }.exceeds_limit(&limits));
+        assert!(GasVector { l1_gas: 0, l2_gas: 0, da_gas: 11 }.exceeds_limit(&limits));
+    }
+
+    #[test]
+    fn gas_vector_fee_sums_per_dimension_products() {
+        let used = GasVector {
+            l1_gas: 2,
+            l2_gas: 3,
+            da_gas: 4,
+        };
+        let prices = GasVector {
+            l1_gas: 10,
+            l2_gas: 100,
+            da_gas: 1_000,
+        };
+        assert_eq!(used.fee(&prices), 2 * 10 + 3 * 100 + 4 * 1_000);
+    }
+
+    #[test]
+    fn gas_computation_mode_defaults_to_single_dimensional() {
+        assert_eq!(
+            CfgEnv::default().gas_computation_mode,
+            GasComputationMode::SingleDimensional
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod cfg;
+
+pub use cfg::{
+    AccountPatch, AnalysisKind, CfgEnv, GasComputationMode, GasSchedule, GasVector,
+    NonceOverflowError, PrecompileActivation,
+};
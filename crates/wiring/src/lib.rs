@@ -0,0 +1,59 @@
+//! Configuration traits and default implementations shared by every revm wiring.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod default;
+
+pub use default::{
+    AccountPatch, AnalysisKind, CfgEnv, GasComputationMode, GasSchedule, GasVector,
+    NonceOverflowError, PrecompileActivation,
+};
+
+use alloc::collections::BTreeMap;
+use primitives::Address;
+
+/// Trait for EVM configuration.
+pub trait Cfg {
+    /// Chain ID of the EVM, it will be compared to the transaction's Chain ID.
+    fn chain_id(&self) -> u64;
+
+    /// Soft/hard limit on contract code size, see [`CfgEnv::limit_contract_code_size`].
+    fn max_code_size(&self) -> usize;
+
+    /// Whether EIP-3607 (reject transactions from senders with deployed code) is disabled.
+    fn is_eip3607_disabled(&self) -> bool;
+
+    /// Whether the sender balance check is disabled.
+    fn is_balance_check_disabled(&self) -> bool;
+
+    /// Whether gas refunds are disabled, see [EIP-3298](https://eips.ethereum.org/EIPS/eip-3298).
+    fn is_gas_refund_disabled(&self) -> bool;
+
+    /// Whether the block gas limit validation is disabled.
+    fn is_block_gas_limit_disabled(&self) -> bool;
+
+    /// Whether the sender nonce check against the account's nonce is disabled.
+    fn is_nonce_check_disabled(&self) -> bool;
+
+    /// Whether EIP-1559 base fee checks are disabled.
+    fn is_base_fee_check_disabled(&self) -> bool;
+
+    /// Opcode gas cost overrides for the active hardfork, see [`GasSchedule`].
+    fn gas_schedule(&self) -> Option<&GasSchedule>;
+
+    /// Call stack depth limit used by CALL/CREATE recursion checks.
+    fn call_stack_limit(&self) -> usize;
+
+    /// Account initialization / empty-account pruning overrides, see [`AccountPatch`].
+    fn account_patch(&self) -> Option<&AccountPatch>;
+
+    /// Per-address precompile activation overrides, see [`PrecompileActivation`].
+    fn precompile_overrides(&self) -> Option<&BTreeMap<Address, PrecompileActivation>>;
+
+    /// Whether the EIP-2681 max nonce check is disabled.
+    fn is_max_nonce_check_disabled(&self) -> bool;
+
+    /// Single- or multi-dimensional gas accounting mode, see [`GasComputationMode`].
+    fn gas_computation_mode(&self) -> &GasComputationMode;
+}